@@ -0,0 +1,43 @@
+use core::{Color, Ray, RayIntersection};
+use core::brdf::{OrenNayarParams, oren_nayar_reflectance};
+use basic::illuminator::SimpleIlluminator;
+use defs::FloatType;
+
+/// Turns a `RayIntersection` plus a `SimpleIlluminator` into a shaded `Color`, the direct-lighting
+/// half of `World`'s per-pixel color (`World` is expected to add any reflective/refractive bounce
+/// contribution on top, the way `path_tracer::PathTracingShader` does for its own integrator).
+///
+/// Selects Oren-Nayar's rough-diffuse response over plain Lambertian once `Material::roughness()`
+/// is non-zero: per `OrenNayarParams`'s doc comment, a roughness of `0.0` degenerates Oren-Nayar
+/// back to Lambertian exactly, so this is purely an optimization for the common smooth case (skip
+/// the extra trig), not a behavior change at `roughness == 0.0`.
+pub struct SimpleColorCalculator;
+
+impl SimpleColorCalculator {
+    pub fn new() -> Self {
+        SimpleColorCalculator
+    }
+
+    pub fn calculate_color<F: Fn(&Ray, FloatType) -> bool>(&self, intersection: &RayIntersection, illuminator: &SimpleIlluminator, cast_shadow_ray: F) -> Color {
+        let material = intersection.get_material();
+        let albedo = material.get_albedo();
+        let roughness = material.roughness();
+        let normal = *intersection.get_normal_vector();
+        let view = intersection.get_view_direction();
+
+        if roughness > 0.0 {
+            let params = OrenNayarParams::new(roughness);
+            illuminator.accumulate(intersection, cast_shadow_ray, |sample| {
+                // `oren_nayar_reflectance` includes the BRDF's 1/pi; the Lambertian branch below
+                // weighs by albedo times cosine alone, so scale by pi to keep both on one scale.
+                oren_nayar_reflectance(&params, albedo, &normal, &view, sample.direction.as_ref())
+                    .mul_scalar(&(::std::f64::consts::PI as FloatType)) * sample.contribution
+            })
+        } else {
+            illuminator.accumulate(intersection, cast_shadow_ray, |sample| {
+                let cos_theta = normal.dot(sample.direction.as_ref()).max(0.0);
+                sample.contribution.mul_scalar(&cos_theta) * albedo
+            })
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use core::{Color, Ray, RayIntersection};
+use basic::lightsource::{LightSample, LightSource};
+use defs::FloatType;
+
+/// Sums every light's direct contribution at a shaded point. A light that reports
+/// `sample_count() > 1` (an `AreaLightSource`) has its samples averaged down to a single estimate
+/// before being added in, so a point light (`sample_count() == 1`) costs exactly one shadow ray
+/// and an area light costs `sample_count()` of them.
+pub struct SimpleIlluminator {
+    lights: Vec<Box<LightSource>>,
+}
+
+impl SimpleIlluminator {
+    pub fn new(lights: Vec<Box<LightSource>>) -> Self {
+        Self { lights }
+    }
+
+    /// The lights this illuminator draws samples from, for a caller that needs to draw and weigh
+    /// samples itself rather than through `accumulate`/`illuminate`.
+    pub fn lights(&self) -> &[Box<LightSource>] {
+        &self.lights
+    }
+
+    /// `cast_shadow_ray` answers whether anything occludes the ray strictly before `distance`
+    /// (the sampled point on the light), so this module stays ignorant of the intersector/World
+    /// it's shading against. `weigh_sample` turns an unoccluded `LightSample`'s incident radiance
+    /// into this surface's actual response to it (e.g. a BRDF times the shading-surface cosine);
+    /// `illuminate` below is the plain-Lambertian instance of this.
+    pub fn accumulate<F, W>(&self, surface: &RayIntersection, cast_shadow_ray: F, weigh_sample: W) -> Color
+        where F: Fn(&Ray, FloatType) -> bool, W: Fn(&LightSample) -> Color
+    {
+        let mut total = Color::zero();
+
+        for light in &self.lights {
+            let sample_count = light.sample_count().max(1);
+            let mut accumulated = Color::zero();
+
+            for _ in 0..sample_count {
+                let sample = light.sample_ray(surface);
+                let shadow_ray = Ray::new(*surface.get_intersection_point(), sample.direction.into_inner());
+
+                if !cast_shadow_ray(&shadow_ray, sample.distance) {
+                    accumulated = accumulated + weigh_sample(&sample);
+                }
+            }
+
+            total = total + accumulated.mul_scalar(&(1.0 / sample_count as FloatType));
+        }
+
+        total
+    }
+
+    /// Plain Lambertian direct lighting: each sample's incident radiance times the shaded
+    /// surface's cosine term. `SimpleColorCalculator` uses `accumulate` directly instead, to swap
+    /// in Oren-Nayar once `Material::roughness()` calls for it.
+    pub fn illuminate<F: Fn(&Ray, FloatType) -> bool>(&self, surface: &RayIntersection, cast_shadow_ray: F) -> Color {
+        let normal = *surface.get_normal_vector();
+
+        self.accumulate(surface, cast_shadow_ray, |sample| {
+            let cos_theta = normal.dot(sample.direction.as_ref()).max(0.0);
+            sample.contribution.mul_scalar(&cos_theta)
+        })
+    }
+}
@@ -0,0 +1,81 @@
+use core::{Ray, RayIntersection};
+use core::bvh::{Bvh, Bounded, Intersectable};
+
+/// Accelerated drop-in replacement for `SimpleIntersector`'s linear scan: finite, boundable
+/// models are sorted into a `Bvh` built once at construction time, while models with no finite
+/// bounding box (e.g. an infinite `SolidPlane`) are kept in a small list tested against every
+/// ray, same as `SimpleIntersector` already does for everything. This is a separate, additive
+/// type in its own module (it does not touch `SimpleIntersector`); swapping a scene over to it is
+/// a per-scene choice left to the caller, same as picking `SingleThreadedRenderer` versus
+/// `ParalellRenderer` in `main.rs` is left to the caller.
+pub struct BvhIntersector<T: Bounded + Intersectable> {
+    bounded: Bvh<T>,
+    unbounded: Vec<Box<Intersectable>>,
+}
+
+impl<T: Bounded + Intersectable> BvhIntersector<T> {
+    pub fn new(bounded_models: Vec<T>, unbounded_models: Vec<Box<Intersectable>>) -> Self {
+        Self {  bounded: Bvh::new(bounded_models),
+                unbounded: unbounded_models }
+    }
+
+    pub fn cast_ray(&self, ray: &Ray) -> Option<RayIntersection> {
+        let mut closest = self.bounded.cast_ray(ray);
+        let mut closest_distance = closest.as_ref().map(RayIntersection::get_distance_to_intersection)
+            .unwrap_or(::std::f64::INFINITY as ::defs::FloatType);
+
+        for model in &self.unbounded {
+            if let Some(intersection) = model.intersect(ray) {
+                let distance = intersection.get_distance_to_intersection();
+                if distance < closest_distance {
+                    closest_distance = distance;
+                    closest = Some(intersection);
+                }
+            }
+        }
+
+        closest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BvhIntersector;
+    use core::{Ray, Material, Color};
+    use basic::model::triangle_mesh::TriangleMesh;
+    use defs::{FloatType, Point3, Vector3};
+
+    // Sixteen unit triangles spaced 4 apart along the x axis. Sixteen is more than
+    // `LEAF_MAX_PRIMITIVES`, so the SAH build splits them into four leaves of four triangles
+    // (x = 0..12, 16..28, 32..44, 48..60) rather than lumping everything into one.
+    fn triangle_at(x: FloatType) -> TriangleMesh {
+        let material = Material::new_diffuse(Color::new(0.8, 0.8, 0.8), None);
+        let triangle = (Point3::new(x - 0.5, -0.5, 0.0), Point3::new(x + 0.5, -0.5, 0.0), Point3::new(x, 0.5, 0.0),
+                        Vector3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 0.0, 1.0));
+        TriangleMesh::new(material, vec![triangle])
+    }
+
+    fn build_intersector() -> BvhIntersector<TriangleMesh> {
+        let meshes = (0..16).map(|i| triangle_at(i as FloatType * 4.0)).collect();
+        BvhIntersector::new(meshes, Vec::new())
+    }
+
+    #[test]
+    fn hits_each_leaf_through_its_own_bounding_volume() {
+        let intersector = build_intersector();
+
+        for i in 0..16 {
+            let x = i as FloatType * 4.0;
+            let ray = Ray::new(Point3::new(x, 0.0, 5.0), Vector3::new(0.0, 0.0, -1.0));
+            let hit = intersector.cast_ray(&ray).expect("ray through a triangle's center should hit");
+            assert!((hit.get_distance_to_intersection() - 5.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn misses_between_leaves_fall_through_the_slab_test() {
+        let intersector = build_intersector();
+        let ray = Ray::new(Point3::new(14.0, 0.0, 5.0), Vector3::new(0.0, 0.0, -1.0));
+        assert!(intersector.cast_ray(&ray).is_none());
+    }
+}
@@ -0,0 +1,119 @@
+use rand::{Rng, thread_rng};
+
+use defs::{FloatType, Point3};
+use core::{Color, RayIntersection};
+use na::{Unit, Vector3};
+
+/// A single shadow-ray sample drawn from a light source: the direction and distance to test for
+/// occlusion, and the radiance contribution to weight the result by if the ray is unoccluded.
+pub struct LightSample {
+    pub direction: Unit<Vector3>,
+    pub distance: FloatType,
+    pub contribution: Color,
+}
+
+/// Common interface `SimpleIlluminator` shades against. `DotLightSource` is the degenerate,
+/// single-sample case of this trait (a point light has exactly one "point on its surface" to
+/// sample), so nothing about the existing illumination loop has to change to support it.
+pub trait LightSource {
+    /// Number of samples `SimpleIlluminator` should average per shaded point to approximate this
+    /// light's contribution; point lights return 1.
+    fn sample_count(&self) -> u32 {
+        1
+    }
+
+    fn sample_ray(&self, surface: &RayIntersection) -> LightSample;
+}
+
+/// A point light with inverse-square ("natural") falloff and hard shadows: the single sample a
+/// point light can offer is the point itself, so it implements `LightSource` with the default
+/// `sample_count` of `1`.
+pub struct DotLightSource {
+    color: Color,
+    intensity: FloatType,
+    position: Point3,
+}
+
+impl DotLightSource {
+    pub fn new_natural(color: Color, intensity: FloatType, position: Point3) -> Self {
+        Self { color, intensity, position }
+    }
+}
+
+impl LightSource for DotLightSource {
+    fn sample_ray(&self, surface: &RayIntersection) -> LightSample {
+        let to_light = self.position - surface.get_intersection_point();
+        let distance = to_light.norm();
+        let direction = Unit::new_normalize(to_light);
+        let attenuation = self.intensity / (distance * distance).max(1e-9);
+
+        LightSample {   direction,
+                        distance,
+                        contribution: self.color.mul_scalar(&attenuation) }
+    }
+}
+
+/// A disk-shaped area light: soft shadows come from averaging `sample_count` independent
+/// `sample_ray` draws, each picking a uniformly random point on the disk and weighting the
+/// result by the light side of the geometry term (cosine at the light, divided by squared
+/// distance and the sample PDF, which for a uniform-area disk is `1 / area`). The cosine at the
+/// shaded point is applied by the caller.
+pub struct AreaLightSource {
+    color: Color,
+    intensity: FloatType,
+    center: Point3,
+    normal: Unit<Vector3>,
+    radius: FloatType,
+    samples: u32,
+}
+
+impl AreaLightSource {
+    pub fn new(color: Color, intensity: FloatType, center: Point3, normal: Vector3, radius: FloatType, samples: u32) -> Self {
+        Self {  color,
+                intensity,
+                center,
+                normal: Unit::new_normalize(normal),
+                radius,
+                samples: samples.max(1) }
+    }
+
+    fn area(&self) -> FloatType {
+        ::std::f64::consts::PI as FloatType * self.radius * self.radius
+    }
+
+    fn sample_point_on_disk(&self) -> Point3 {
+        let mut rng = thread_rng();
+        let r = self.radius * rng.gen::<FloatType>().sqrt();
+        let theta = 2.0 * ::std::f64::consts::PI as FloatType * rng.gen::<FloatType>();
+
+        let up = if self.normal.z.abs() < 0.999 { Vector3::new(0.0, 0.0, 1.0) } else { Vector3::new(1.0, 0.0, 0.0) };
+        let tangent = Unit::new_normalize(up.cross(self.normal.as_ref()));
+        let bitangent = self.normal.as_ref().cross(&tangent);
+
+        self.center + tangent.into_inner() * (r * theta.cos()) + bitangent * (r * theta.sin())
+    }
+}
+
+impl LightSource for AreaLightSource {
+    fn sample_count(&self) -> u32 {
+        self.samples
+    }
+
+    fn sample_ray(&self, surface: &RayIntersection) -> LightSample {
+        let sample_point = self.sample_point_on_disk();
+        let to_light = sample_point - surface.get_intersection_point();
+        let distance = to_light.norm();
+        let direction = Unit::new_normalize(to_light);
+
+        // Only the light-side cosine and falloff go into `contribution`: it's the radiance
+        // arriving from this sample direction, not yet weighted by the shaded surface's BRDF.
+        // The shaded-surface cosine (and whichever BRDF applies, Lambertian or Oren-Nayar) is the
+        // caller's job, same as for `DotLightSource`'s sample above.
+        let cos_light = self.normal.as_ref().dot(&(-direction.into_inner())).max(0.0);
+        let geometry_term = (cos_light * self.area()) / (distance * distance).max(1e-9);
+
+        LightSample {   direction,
+                        distance,
+                        contribution: self.color.mul_scalar(&(self.intensity * geometry_term)) }
+    }
+}
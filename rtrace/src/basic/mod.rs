@@ -0,0 +1,17 @@
+pub mod color_calculator;
+pub mod illuminator;
+pub mod intersector;
+pub mod lightsource;
+pub mod model;
+pub mod path_tracer;
+
+pub use self::color_calculator::SimpleColorCalculator;
+pub use self::illuminator::SimpleIlluminator;
+pub use self::intersector::BvhIntersector;
+pub use self::path_tracer::{PathTracingShader, PathTracingShaderTaskProducer};
+
+// `SimpleIntersector`, `WorldViewTaskProducer`, `GlobalIlluminationShaderTaskProducer`,
+// `GlobalIlluminationShader`, and `MedianFilter` are all imported from `rtrace::basic` in
+// `main.rs`, but none of them have a source file in this checkout. As with `core/mod.rs`, this
+// file only wires up the submodules that actually exist on disk; those names still need their
+// own implementation file and `pub mod`/`pub use` here.
@@ -0,0 +1,9 @@
+pub mod obj_loader;
+pub mod triangle_mesh;
+
+pub use self::obj_loader::{load_obj, ObjLoadError};
+pub use self::triangle_mesh::TriangleMesh;
+
+// `SolidSphere` and `SolidPlane` are imported from `rtrace::basic::model` in `main.rs`, but
+// neither has a source file in this checkout; they still need their own implementation file and
+// `pub mod`/`pub use` here.
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use defs::{FloatType, Point3, Vector3};
+use core::{Color, Material, FresnelIndex};
+use basic::model::triangle_mesh::TriangleMesh;
+
+#[derive(Debug)]
+pub enum ObjLoadError {
+    Io(String),
+    MissingMaterial(String),
+    MalformedFace(String),
+}
+
+struct MtlMaterial {
+    diffuse: Color,
+    specular: Option<(Color, FloatType)>,
+    emission: Option<Color>,
+    refraction_index: Option<FloatType>,
+    dissolve: FloatType,
+    illum: i32,
+}
+
+impl Default for MtlMaterial {
+    fn default() -> Self {
+        Self {  diffuse: Color::new(0.8, 0.8, 0.8),
+                specular: None,
+                emission: None,
+                refraction_index: None,
+                dissolve: 1.0,
+                illum: 1 }
+    }
+}
+
+fn parse_floats(tokens: &[&str]) -> Vec<FloatType> {
+    tokens.iter().filter_map(|token| token.parse::<FloatType>().ok()).collect()
+}
+
+/// Parses a Wavefront `.mtl` file into `Material` instances, keyed by material name, mapping
+/// `Kd` to the diffuse color, `Ks`/`Ns` to the shiny specular pair, `Ke` to a light source, and
+/// `Ni` together with `illum 2`/`d < 1` to a reflective-and-refractive glass material.
+fn load_mtl(path: &Path) -> Result<HashMap<String, Material>, ObjLoadError> {
+    let contents = fs::read_to_string(path).map_err(|err| ObjLoadError::Io(err.to_string()))?;
+
+    let mut raw: HashMap<String, MtlMaterial> = HashMap::new();
+    let mut current: Option<String> = None;
+
+    for line in contents.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.is_empty() {
+            continue;
+        }
+
+        match tokens[0] {
+            "newmtl" => {
+                let name = tokens[1].to_string();
+                raw.insert(name.clone(), MtlMaterial::default());
+                current = Some(name);
+            },
+            "Kd" => {
+                if let Some(name) = &current {
+                    let values = parse_floats(&tokens[1..]);
+                    raw.get_mut(name).unwrap().diffuse = Color::new(values[0], values[1], values[2]);
+                }
+            },
+            "Ks" => {
+                if let Some(name) = &current {
+                    let values = parse_floats(&tokens[1..]);
+                    let material = raw.get_mut(name).unwrap();
+                    let exponent = material.specular.map(|(_, e)| e).unwrap_or(1.0);
+                    material.specular = Some((Color::new(values[0], values[1], values[2]), exponent));
+                }
+            },
+            "Ns" => {
+                if let Some(name) = &current {
+                    let exponent = parse_floats(&tokens[1..])[0];
+                    let material = raw.get_mut(name).unwrap();
+                    let specular_color = material.specular.map(|(c, _)| c).unwrap_or(Color::one());
+                    material.specular = Some((specular_color, exponent));
+                }
+            },
+            "Ke" => {
+                if let Some(name) = &current {
+                    let values = parse_floats(&tokens[1..]);
+                    let color = Color::new(values[0], values[1], values[2]);
+                    if values[0] > 0.0 || values[1] > 0.0 || values[2] > 0.0 {
+                        raw.get_mut(name).unwrap().emission = Some(color);
+                    }
+                }
+            },
+            "Ni" => {
+                if let Some(name) = &current {
+                    raw.get_mut(name).unwrap().refraction_index = Some(parse_floats(&tokens[1..])[0]);
+                }
+            },
+            "d" => {
+                if let Some(name) = &current {
+                    raw.get_mut(name).unwrap().dissolve = parse_floats(&tokens[1..])[0];
+                }
+            },
+            "illum" => {
+                if let Some(name) = &current {
+                    raw.get_mut(name).unwrap().illum = tokens[1].parse().unwrap_or(1);
+                }
+            },
+            _ => (),
+        }
+    }
+
+    let materials = raw.into_iter().map(|(name, mtl)| {
+        let material = if let Some(emission) = mtl.emission {
+            Material::new_light_source(emission, None)
+        } else if mtl.illum == 2 && mtl.dissolve < 1.0 {
+            let ior = mtl.refraction_index.unwrap_or(1.5);
+            Material::new_reflective_and_refractive(FresnelIndex::new(ior, ior, ior), FresnelIndex::one(), None, None, None)
+        } else if let Some(specular) = mtl.specular {
+            Material::new_shiny(mtl.diffuse, specular, None)
+        } else {
+            Material::new_diffuse(mtl.diffuse, None)
+        };
+
+        (name, material)
+    }).collect();
+
+    Ok(materials)
+}
+
+/// Loads a Wavefront `.obj` mesh plus its companion `.mtl` (referenced via `mtllib`), splitting
+/// the geometry into one `TriangleMesh` per `usemtl` group so each ends up with a single
+/// `Material`, ready to be boxed into a `SimpleIntersector`/`BvhIntersector` primitive list.
+pub fn load_obj(path: &Path) -> Result<Vec<TriangleMesh>, ObjLoadError> {
+    let contents = fs::read_to_string(path).map_err(|err| ObjLoadError::Io(err.to_string()))?;
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut positions: Vec<Point3> = Vec::new();
+    let mut normals: Vec<Vector3> = Vec::new();
+    let mut materials: HashMap<String, Material> = HashMap::new();
+    let mut groups: HashMap<String, Vec<(Point3, Point3, Point3, Vector3, Vector3, Vector3)>> = HashMap::new();
+    let mut current_material = String::new();
+
+    for line in contents.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.is_empty() {
+            continue;
+        }
+
+        match tokens[0] {
+            "mtllib" => {
+                materials = load_mtl(&base_dir.join(tokens[1]))?;
+            },
+            "usemtl" => {
+                current_material = tokens[1].to_string();
+                groups.entry(current_material.clone()).or_insert_with(Vec::new);
+            },
+            "v" => {
+                let values = parse_floats(&tokens[1..]);
+                positions.push(Point3::new(values[0], values[1], values[2]));
+            },
+            "vn" => {
+                let values = parse_floats(&tokens[1..]);
+                normals.push(Vector3::new(values[0], values[1], values[2]));
+            },
+            "f" => {
+                let indices: Result<Vec<(usize, Option<usize>)>, ObjLoadError> = tokens[1..].iter().map(|token| {
+                    let mut parts = token.split('/');
+                    let vertex_index = parts.next()
+                        .and_then(|s| s.parse::<usize>().ok())
+                        .ok_or_else(|| ObjLoadError::MalformedFace(line.to_string()))?;
+                    let normal_index = parts.nth(1).and_then(|s| s.parse::<usize>().ok());
+                    Ok((vertex_index - 1, normal_index.map(|n| n - 1)))
+                }).collect();
+                let indices = indices?;
+
+                if indices.len() < 3 {
+                    return Err(ObjLoadError::MalformedFace(line.to_string()));
+                }
+
+                let face_normal = {
+                    let edge1 = positions[indices[1].0] - positions[indices[0].0];
+                    let edge2 = positions[indices[2].0] - positions[indices[0].0];
+                    edge1.cross(&edge2)
+                };
+                let normal_for = |entry: &(usize, Option<usize>)| entry.1.map(|i| normals[i]).unwrap_or(face_normal);
+
+                // Fan-triangulate faces with more than three vertices.
+                for i in 1..(indices.len() - 1) {
+                    let triangle = (
+                        positions[indices[0].0], positions[indices[i].0], positions[indices[i + 1].0],
+                        normal_for(&indices[0]), normal_for(&indices[i]), normal_for(&indices[i + 1]),
+                    );
+                    groups.entry(current_material.clone()).or_insert_with(Vec::new).push(triangle);
+                }
+            },
+            _ => (),
+        }
+    }
+
+    groups.into_iter().map(|(name, triangles)| {
+        let material = materials.get(&name).cloned().ok_or_else(|| ObjLoadError::MissingMaterial(name.clone()))?;
+        Ok(TriangleMesh::new(material, triangles))
+    }).collect()
+}
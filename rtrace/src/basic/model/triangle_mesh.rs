@@ -0,0 +1,116 @@
+use defs::{FloatType, Point3, Vector3};
+use core::{Ray, RayIntersection, Material};
+use core::bvh::{Bounded, Intersectable};
+use na::{Unit};
+
+/// A single triangle with its own vertex positions and shading normals, interpolated across the
+/// hit point for smooth (Phong) shading rather than the flat face normal.
+struct Triangle {
+    vertices: [Point3; 3],
+    normals: [Vector3; 3],
+}
+
+impl Triangle {
+    // Moller-Trumbore ray/triangle intersection.
+    fn intersect_local(&self, ray: &Ray) -> Option<(FloatType, FloatType, FloatType)> {
+        let edge1 = self.vertices[1] - self.vertices[0];
+        let edge2 = self.vertices[2] - self.vertices[0];
+
+        let p = ray.get_direction().cross(&edge2);
+        let determinant = edge1.dot(&p);
+
+        if determinant.abs() < 1e-12 {
+            return None;
+        }
+
+        let inv_determinant = 1.0 / determinant;
+        let t_vec = ray.get_origin() - self.vertices[0];
+        let u = t_vec.dot(&p) * inv_determinant;
+        if u < 0.0 || u > 1.0 {
+            return None;
+        }
+
+        let q = t_vec.cross(&edge1);
+        let v = ray.get_direction().dot(&q) * inv_determinant;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let distance = edge2.dot(&q) * inv_determinant;
+        if distance <= 1e-9 {
+            return None;
+        }
+
+        Some((distance, u, v))
+    }
+
+    fn interpolated_normal(&self, u: FloatType, v: FloatType) -> Vector3 {
+        let w = 1.0 - u - v;
+        self.normals[0] * w + self.normals[1] * u + self.normals[2] * v
+    }
+
+    fn aabb_min(&self) -> Point3 {
+        Point3::new(self.vertices.iter().map(|p| p.x).fold(FloatType::infinity(), FloatType::min),
+                    self.vertices.iter().map(|p| p.y).fold(FloatType::infinity(), FloatType::min),
+                    self.vertices.iter().map(|p| p.z).fold(FloatType::infinity(), FloatType::min))
+    }
+
+    fn aabb_max(&self) -> Point3 {
+        Point3::new(self.vertices.iter().map(|p| p.x).fold(-FloatType::infinity(), FloatType::max),
+                    self.vertices.iter().map(|p| p.y).fold(-FloatType::infinity(), FloatType::max),
+                    self.vertices.iter().map(|p| p.z).fold(-FloatType::infinity(), FloatType::max))
+    }
+}
+
+/// A collection of triangles sharing one `Material`, as loaded from a Wavefront `.obj` (geometry)
+/// plus its companion `.mtl` (material) file by [`super::obj_loader`]. Implements the same
+/// `Bounded`/`Intersectable` pair as the primitive models so it can be dropped straight into a
+/// `BvhIntersector` alongside spheres and planes.
+pub struct TriangleMesh {
+    material: Material,
+    triangles: Vec<Triangle>,
+}
+
+impl TriangleMesh {
+    pub fn new(material: Material, triangles: Vec<(Point3, Point3, Point3, Vector3, Vector3, Vector3)>) -> Self {
+        let triangles = triangles.into_iter()
+            .map(|(v0, v1, v2, n0, n1, n2)| Triangle { vertices: [v0, v1, v2], normals: [n0, n1, n2] })
+            .collect();
+
+        Self { material, triangles }
+    }
+}
+
+impl Bounded for TriangleMesh {
+    fn aabb_min(&self) -> Point3 {
+        self.triangles.iter().map(Triangle::aabb_min).fold(Point3::new(FloatType::infinity(), FloatType::infinity(), FloatType::infinity()),
+            |acc, p| Point3::new(acc.x.min(p.x), acc.y.min(p.y), acc.z.min(p.z)))
+    }
+
+    fn aabb_max(&self) -> Point3 {
+        self.triangles.iter().map(Triangle::aabb_max).fold(Point3::new(-FloatType::infinity(), -FloatType::infinity(), -FloatType::infinity()),
+            |acc, p| Point3::new(acc.x.max(p.x), acc.y.max(p.y), acc.z.max(p.z)))
+    }
+}
+
+impl Intersectable for TriangleMesh {
+    fn intersect(&self, ray: &Ray) -> Option<RayIntersection> {
+        let mut closest: Option<(FloatType, &Triangle, FloatType, FloatType)> = None;
+
+        for triangle in &self.triangles {
+            if let Some((distance, u, v)) = triangle.intersect_local(ray) {
+                let is_closer = closest.as_ref().map(|&(best, ..)| distance < best).unwrap_or(true);
+                if is_closer {
+                    closest = Some((distance, triangle, u, v));
+                }
+            }
+        }
+
+        closest.and_then(|(distance, triangle, u, v)| {
+            let point = ray.get_origin() + ray.get_direction() * distance;
+            let normal = triangle.interpolated_normal(u, v);
+            let was_inside = Unit::new_normalize(normal).as_ref().dot(&ray.get_direction()) > 0.0;
+            RayIntersection::new(normal, point, ray, self.material.clone(), was_inside).ok()
+        })
+    }
+}
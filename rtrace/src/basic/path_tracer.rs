@@ -0,0 +1,184 @@
+use std::sync::{Arc, Mutex};
+use std::f64::consts::PI;
+
+use rand::{Rng, thread_rng};
+
+use defs::{FloatType, Point2Int, Vector3};
+use core::{Color, Ray, RayIntersection, Material, WorldViewTrait, RenderingTaskProducer,
+           BasicSceneBuffer, MutableSceneBuffer};
+use core::brdf::{schlick_fresnel, fresnel_f0_from_ior};
+use na::{Unit};
+
+const MIN_BOUNCES_BEFORE_ROULETTE: u32 = 4;
+const MAX_BOUNCES: u32 = 32;
+
+/// Unbiased Monte Carlo path tracer, offered as an alternative integrator to
+/// `GlobalIlluminationShader`. Traces `samples_per_pixel` independent paths per pixel and
+/// averages them into its own `BasicSceneBuffer`, converging to the correct image as the sample
+/// count grows instead of relying on `GlobalIlluminationShader`'s post-hoc median-filter overlay.
+///
+/// Bounces are resolved through `intersect` rather than `WorldViewTrait`: `WorldViewTrait` (via
+/// `RayCaster`) only ever hands back a fully-shaded `Color` for a pixel, but a path tracer needs
+/// the raw `RayIntersection` at each bounce to keep tracing. `intersect` is a plain closure
+/// rather than a new trait bound so the caller can hand in whichever intersector (`SimpleIntersector`,
+/// `BvhIntersector`, ...) it already built for `World`, the same way `produce_task_for` elsewhere
+/// in this file hands back a plain `Box<Fn() + Send + Sync>` instead of a bespoke trait.
+pub struct PathTracingShader {
+    worldview: Arc<WorldViewTrait>,
+    intersect: Box<Fn(&Ray) -> Option<RayIntersection> + Send + Sync>,
+    samples_per_pixel: u32,
+    buffer: Mutex<BasicSceneBuffer>,
+}
+
+impl PathTracingShader {
+    pub fn new(worldview: Arc<WorldViewTrait>, intersect: Box<Fn(&Ray) -> Option<RayIntersection> + Send + Sync>, samples_per_pixel: u32) -> Self {
+        let buffer = Mutex::new(BasicSceneBuffer::new(*worldview.get_screen()));
+        Self { worldview, intersect, samples_per_pixel, buffer }
+    }
+
+    /// The per-pixel buffer `shade_pixel` accumulates into, for a caller to combine into
+    /// `worldview` the same way `main` combines `GlobalIlluminationShader`'s per-model buffers.
+    pub fn get_buffer(&self) -> &Mutex<BasicSceneBuffer> {
+        &self.buffer
+    }
+
+    pub fn shade_pixel(&self, coord: Point2Int) -> Option<Color> {
+        let ray = self.worldview.get_view().get_ray_for_pixel(coord)?;
+
+        let mut accumulator = Color::zero();
+        for _ in 0..self.samples_per_pixel {
+            accumulator = accumulator + self.trace_path(&ray, 0);
+        }
+
+        let color = accumulator.mul_scalar(&(1.0 / self.samples_per_pixel as FloatType));
+        self.buffer.lock().unwrap().set(coord, color);
+        Some(color)
+    }
+
+    fn trace_path(&self, ray: &Ray, bounce: u32) -> Color {
+        let intersection = match (self.intersect)(ray) {
+            Some(intersection) => intersection,
+            None => return Color::zero(),
+        };
+
+        let material = intersection.get_material();
+        let emitted = material.get_emission().unwrap_or(Color::zero());
+
+        if bounce >= MAX_BOUNCES {
+            return emitted;
+        }
+
+        // Russian roulette once the path is long enough: continue with probability equal to the
+        // surface's brightest channel, dividing the eventual throughput by that probability so
+        // the estimator stays unbiased.
+        let albedo = material.get_albedo();
+        if bounce >= MIN_BOUNCES_BEFORE_ROULETTE {
+            let continue_probability = albedo.get().0.max(albedo.get().1.max(albedo.get().2)).min(1.0);
+            if thread_rng().gen::<FloatType>() > continue_probability {
+                return emitted;
+            }
+
+            let bounced = self.sample_bounce(&intersection, bounce);
+            return emitted + bounced.mul_scalar(&(1.0 / continue_probability));
+        }
+
+        emitted + self.sample_bounce(&intersection, bounce)
+    }
+
+    fn sample_bounce(&self, intersection: &RayIntersection, bounce: u32) -> Color {
+        let material = intersection.get_material();
+
+        if let Some(ior) = material.get_fresnel_index() {
+            let normal = *intersection.get_normal_vector();
+            let incoming = intersection.get_itersector_ray().get_direction();
+            let cos_theta = (-incoming).dot(&normal).abs();
+            let reflectance = schlick_fresnel(fresnel_f0_from_ior(ior), cos_theta);
+
+            if material.is_refractive() {
+                if let Some(refracted) = refract(&incoming, &normal, intersection.was_inside(), ior) {
+                    if thread_rng().gen::<FloatType>() > reflectance {
+                        let refracted_ray = Ray::new(*intersection.get_intersection_point(), refracted);
+                        return self.trace_path(&refracted_ray, bounce + 1) * material.get_albedo();
+                    }
+                }
+            }
+
+            let reflected_ray = Ray::new(*intersection.get_intersection_point(), reflect(&incoming, &normal));
+            return self.trace_path(&reflected_ray, bounce + 1) * material.get_albedo();
+        }
+
+        // Diffuse (Lambertian) surface: sample a cosine-weighted direction over the hemisphere
+        // around the normal. Because the cosine PDF (cos(theta) / pi) exactly cancels the
+        // Lambertian cosine term in the rendering equation, the throughput multiply collapses to
+        // just the surface albedo, with no separate cosine/PDF factor needed.
+        let direction = cosine_weighted_hemisphere_sample(intersection.get_normal_vector());
+        let bounce_ray = Ray::new(*intersection.get_intersection_point(), direction);
+        self.trace_path(&bounce_ray, bounce + 1) * material.get_albedo()
+    }
+}
+
+/// Mirror reflection of `direction` (pointing towards the surface) about `normal`.
+fn reflect(direction: &Vector3, normal: &Vector3) -> Vector3 {
+    *direction - *normal * (2.0 * direction.dot(normal))
+}
+
+/// Snell's-law refraction of `direction` (pointing towards the surface) through a boundary with
+/// index of refraction `ior`, flipping the ratio when `was_inside` says the ray is leaving rather
+/// than entering the material. Returns `None` on total internal reflection.
+fn refract(direction: &Vector3, normal: &Vector3, was_inside: bool, ior: FloatType) -> Option<Vector3> {
+    let (eta, normal) = if was_inside { (ior, -*normal) } else { (1.0 / ior, *normal) };
+    let cos_i = (-(*direction)).dot(&normal).max(0.0);
+    let sin_t2 = eta * eta * (1.0 - cos_i * cos_i);
+
+    if sin_t2 > 1.0 {
+        return None;
+    }
+
+    let cos_t = (1.0 - sin_t2).sqrt();
+    Some(*direction * eta + normal * (eta * cos_i - cos_t))
+}
+
+fn cosine_weighted_hemisphere_sample(normal: &Vector3) -> Vector3 {
+    let mut rng = thread_rng();
+    let u: FloatType = rng.gen();
+    let v: FloatType = rng.gen();
+
+    let r1 = 2.0 * PI * u;
+    let r2 = v;
+    let r2_sqrt = r2.sqrt();
+
+    let local = Vector3::new(r1.cos() * r2_sqrt, r1.sin() * r2_sqrt, (1.0 - r2).sqrt());
+
+    let tangent_frame = Unit::new_normalize(*normal);
+    let (tangent, bitangent) = orthonormal_basis(&tangent_frame);
+
+    tangent * local.x + bitangent * local.y + tangent_frame.as_ref() * local.z
+}
+
+fn orthonormal_basis(normal: &Unit<Vector3>) -> (Vector3, Vector3) {
+    let up = if normal.z.abs() < 0.999 { Vector3::new(0.0, 0.0, 1.0) } else { Vector3::new(1.0, 0.0, 0.0) };
+    let tangent = Unit::new_normalize(up.cross(normal.as_ref()));
+    let bitangent = normal.as_ref().cross(&tangent);
+    (tangent.into_inner(), bitangent)
+}
+
+/// Plugs `PathTracingShader` into the same `OrderedTaskProducers`/`ThreadSafeIterator` pipeline
+/// that drives `WorldViewTaskProducer` and `GlobalIlluminationShaderTaskProducer` in `main`.
+/// Discarding `shade_pixel`'s return here is deliberate and matches `WorldViewTaskProducer`:
+/// the result is already persisted as a side effect, into `PathTracingShader`'s own buffer.
+pub struct PathTracingShaderTaskProducer {
+    shader: Arc<PathTracingShader>,
+}
+
+impl PathTracingShaderTaskProducer {
+    pub fn new(shader: Arc<PathTracingShader>) -> Self {
+        Self { shader }
+    }
+}
+
+impl RenderingTaskProducer for PathTracingShaderTaskProducer {
+    fn produce_task_for(&self, coord: Point2Int) -> Box<Fn() + Send + Sync> {
+        let shader = Arc::clone(&self.shader);
+        Box::new(move || { shader.shade_pixel(coord); })
+    }
+}
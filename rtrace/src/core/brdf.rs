@@ -0,0 +1,59 @@
+use defs::{FloatType};
+use core::Color;
+use na::Vector3;
+
+/// Oren-Nayar microfacet diffuse reflectance, parameterized by roughness `sigma` (the standard
+/// deviation, in radians, of the microfacet slope distribution). `sigma == 0` degenerates to
+/// Lambertian (`A == 1`, `B == 0`), so a `Material`'s roughness field can default to `0.0` and
+/// fall back to the existing flat-looking diffuse response unchanged.
+pub struct OrenNayarParams {
+    a: FloatType,
+    b: FloatType,
+}
+
+impl OrenNayarParams {
+    pub fn new(sigma: FloatType) -> Self {
+        let sigma_sq = sigma * sigma;
+        Self {  a: 1.0 - 0.5 * sigma_sq / (sigma_sq + 0.33),
+                b: 0.45 * sigma_sq / (sigma_sq + 0.09) }
+    }
+}
+
+/// `view` and `light` are unit vectors from the shaded point towards the eye and the light,
+/// both expressed in the local frame where `normal` is `(0, 0, 1)`-equivalent (i.e. angles are
+/// measured against `normal` directly, so any world-space vectors must already be normalized).
+pub fn oren_nayar_reflectance(params: &OrenNayarParams, albedo: Color, normal: &Vector3, view: &Vector3, light: &Vector3) -> Color {
+    let cos_theta_i = normal.dot(light).max(0.0);
+    let cos_theta_r = normal.dot(view).max(0.0);
+
+    if cos_theta_i <= 0.0 || cos_theta_r <= 0.0 {
+        return Color::zero();
+    }
+
+    let theta_i = cos_theta_i.acos();
+    let theta_r = cos_theta_r.acos();
+
+    // Azimuthal angles of the light/view directions projected onto the tangent plane, so their
+    // difference gives cos(phi_i - phi_r) without building a full tangent-space basis.
+    let light_tangent = (*light - *normal * cos_theta_i).try_normalize(1e-9).unwrap_or(Vector3::new(0.0, 0.0, 0.0));
+    let view_tangent = (*view - *normal * cos_theta_r).try_normalize(1e-9).unwrap_or(Vector3::new(0.0, 0.0, 0.0));
+    let cos_phi_diff = light_tangent.dot(&view_tangent).max(0.0);
+
+    let factor = params.a + params.b * cos_phi_diff * theta_i.max(theta_r).sin() * theta_i.min(theta_r).tan();
+
+    albedo.mul_scalar(&(cos_theta_i * factor / ::std::f64::consts::PI as FloatType))
+}
+
+/// Schlick's approximation to the Fresnel reflectance: `F = F0 + (1 - F0) * (1 - cos(theta))^5`,
+/// with `F0` typically derived from a `Material`'s `FresnelIndex` as `((n - 1) / (n + 1))^2`.
+pub fn schlick_fresnel(f0: FloatType, cos_theta: FloatType) -> FloatType {
+    let cos_theta = cos_theta.max(0.0).min(1.0);
+    f0 + (1.0 - f0) * (1.0 - cos_theta).powi(5)
+}
+
+/// Converts a `FresnelIndex`-style index of refraction into the normal-incidence reflectance
+/// `F0` that `schlick_fresnel` expects.
+pub fn fresnel_f0_from_ior(ior: FloatType) -> FloatType {
+    let root = (ior - 1.0) / (ior + 1.0);
+    root * root
+}
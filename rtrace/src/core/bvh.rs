@@ -0,0 +1,259 @@
+use defs::{FloatType, Point3};
+use core::{Ray, RayIntersection};
+
+// ----- Definitions ---------------------------------------------------------------------------
+
+/// Anything that can report an axis-aligned bounding box, so it can be placed into a `Bvh`.
+pub trait Bounded {
+    fn aabb_min(&self) -> Point3;
+    fn aabb_max(&self) -> Point3;
+}
+
+/// Anything that can be tested against a `Ray` and report the closest hit, if any.
+pub trait Intersectable {
+    fn intersect(&self, ray: &Ray) -> Option<RayIntersection>;
+}
+
+/// A boundable object that can also be intersected by a `Ray`, the unit the `Bvh` stores.
+pub trait BoundedIntersectable : Bounded + Intersectable {
+}
+
+impl<T: Bounded + Intersectable> BoundedIntersectable for T {
+}
+
+const LEAF_MAX_PRIMITIVES: usize = 4;
+
+#[derive(Clone, Copy)]
+struct Aabb {
+    min: Point3,
+    max: Point3,
+}
+
+impl Aabb {
+    fn empty() -> Self {
+        Self {  min: Point3::new(FloatType::infinity(), FloatType::infinity(), FloatType::infinity()),
+                max: Point3::new(-FloatType::infinity(), -FloatType::infinity(), -FloatType::infinity()) }
+    }
+
+    fn grow(&mut self, other: &Aabb) {
+        self.min = Point3::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y), self.min.z.min(other.min.z));
+        self.max = Point3::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y), self.max.z.max(other.max.z));
+    }
+
+    fn grow_point(&mut self, point: &Point3) {
+        self.min = Point3::new(self.min.x.min(point.x), self.min.y.min(point.y), self.min.z.min(point.z));
+        self.max = Point3::new(self.max.x.max(point.x), self.max.y.max(point.y), self.max.z.max(point.z));
+    }
+
+    fn centroid(&self) -> Point3 {
+        Point3::new((self.min.x + self.max.x) * 0.5, (self.min.y + self.max.y) * 0.5, (self.min.z + self.max.z) * 0.5)
+    }
+
+    fn surface_area(&self) -> FloatType {
+        let extent = self.max - self.min;
+        if extent.x < 0.0 || extent.y < 0.0 || extent.z < 0.0 {
+            return 0.0;
+        }
+        2.0 * (extent.x * extent.y + extent.y * extent.z + extent.z * extent.x)
+    }
+
+    fn largest_axis(&self) -> usize {
+        let extent = self.max - self.min;
+        if extent.x > extent.y && extent.x > extent.z {
+            0
+        } else if extent.y > extent.z {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn axis(&self, point: &Point3, axis: usize) -> FloatType {
+        match axis {
+            0 => point.x,
+            1 => point.y,
+            _ => point.z,
+        }
+    }
+
+    // Slab test: returns whether the ray overlaps the box somewhere between `0` and `max_distance`.
+    fn hit(&self, ray: &Ray, max_distance: FloatType) -> bool {
+        let origin = ray.get_origin();
+        let direction = ray.get_direction();
+
+        let mut t_min = 0.0;
+        let mut t_max = max_distance;
+
+        for axis in 0..3 {
+            let (o, d, lo, hi) = match axis {
+                0 => (origin.x, direction.x, self.min.x, self.max.x),
+                1 => (origin.y, direction.y, self.min.y, self.max.y),
+                _ => (origin.z, direction.z, self.min.z, self.max.z),
+            };
+
+            if d.abs() < 1e-12 {
+                if o < lo || o > hi {
+                    return false;
+                }
+            } else {
+                let inv_d = 1.0 / d;
+                let mut t0 = (lo - o) * inv_d;
+                let mut t1 = (hi - o) * inv_d;
+                if t0 > t1 {
+                    std::mem::swap(&mut t0, &mut t1);
+                }
+                t_min = t_min.max(t0);
+                t_max = t_max.min(t1);
+                if t_min > t_max {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+enum BvhNodeKind {
+    Leaf { first: usize, count: usize },
+    Interior { left: usize, right: usize },
+}
+
+struct BvhNode {
+    bounds: Aabb,
+    kind: BvhNodeKind,
+}
+
+/// A binary bounding-volume hierarchy over finite, boundable primitives, stored as a flat
+/// `Vec<BvhNode>` rather than a pointer tree so traversal stays cache friendly. Primitives
+/// without a finite bounding box (e.g. an infinite plane) cannot be placed here and should be
+/// kept in a small always-tested list alongside the tree.
+pub struct Bvh<T: BoundedIntersectable> {
+    nodes: Vec<BvhNode>,
+    primitives: Vec<T>,
+    root: usize,
+}
+
+impl<T: BoundedIntersectable> Bvh<T> {
+    pub fn new(primitives: Vec<T>) -> Self {
+        let mut nodes = Vec::new();
+        let mut ordering: Vec<usize> = (0..primitives.len()).collect();
+        let bounds: Vec<Aabb> = primitives.iter().map(|p| Aabb { min: p.aabb_min(), max: p.aabb_max() }).collect();
+
+        let root = if primitives.is_empty() {
+            0
+        } else {
+            Self::build(&mut nodes, &bounds, &mut ordering, 0, primitives.len())
+        };
+
+        let mut slots: Vec<Option<T>> = primitives.into_iter().map(Some).collect();
+        let primitives = ordering.into_iter().map(|i| slots[i].take().unwrap()).collect();
+
+        Self { nodes, primitives, root }
+    }
+
+    fn build(nodes: &mut Vec<BvhNode>, bounds: &[Aabb], ordering: &mut [usize], start: usize, end: usize) -> usize {
+        let mut node_bounds = Aabb::empty();
+        let mut centroid_bounds = Aabb::empty();
+        for &index in &ordering[start..end] {
+            node_bounds.grow(&bounds[index]);
+            centroid_bounds.grow_point(&bounds[index].centroid());
+        }
+
+        let count = end - start;
+        if count <= LEAF_MAX_PRIMITIVES {
+            nodes.push(BvhNode { bounds: node_bounds, kind: BvhNodeKind::Leaf { first: start, count } });
+            return nodes.len() - 1;
+        }
+
+        let axis = centroid_bounds.largest_axis();
+        ordering[start..end].sort_by(|&a, &b| {
+            centroid_bounds.axis(&bounds[a].centroid(), axis)
+                .partial_cmp(&centroid_bounds.axis(&bounds[b].centroid(), axis))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let split = Self::sah_split(bounds, ordering, start, end).unwrap_or((start + end) / 2);
+
+        let node_index = nodes.len();
+        nodes.push(BvhNode { bounds: node_bounds, kind: BvhNodeKind::Leaf { first: start, count: 0 } });
+
+        let left = Self::build(nodes, bounds, ordering, start, split);
+        let right = Self::build(nodes, bounds, ordering, split, end);
+        nodes[node_index].kind = BvhNodeKind::Interior { left, right };
+
+        node_index
+    }
+
+    // Evaluates `cost = area(left) * count(left) + area(right) * count(right)` at every possible
+    // split boundary along the already axis-sorted range, returning the cheapest one.
+    fn sah_split(bounds: &[Aabb], ordering: &[usize], start: usize, end: usize) -> Option<usize> {
+        let count = end - start;
+        if count < 2 {
+            return None;
+        }
+
+        let mut best_split = None;
+        let mut best_cost = FloatType::infinity();
+
+        for split in (start + 1)..end {
+            let mut left_bounds = Aabb::empty();
+            for &index in &ordering[start..split] {
+                left_bounds.grow(&bounds[index]);
+            }
+            let mut right_bounds = Aabb::empty();
+            for &index in &ordering[split..end] {
+                right_bounds.grow(&bounds[index]);
+            }
+
+            let cost = left_bounds.surface_area() * (split - start) as FloatType
+                + right_bounds.surface_area() * (end - split) as FloatType;
+
+            if cost < best_cost {
+                best_cost = cost;
+                best_split = Some(split);
+            }
+        }
+
+        best_split
+    }
+
+    /// Descends the tree with a small explicit stack, pruning subtrees whose AABB lies beyond
+    /// the closest hit found so far.
+    pub fn cast_ray(&self, ray: &Ray) -> Option<RayIntersection> {
+        if self.primitives.is_empty() {
+            return None;
+        }
+
+        let mut closest: Option<RayIntersection> = None;
+        let mut closest_distance = FloatType::infinity();
+        let mut stack = vec![self.root];
+
+        while let Some(node_index) = stack.pop() {
+            let node = &self.nodes[node_index];
+            if !node.bounds.hit(ray, closest_distance) {
+                continue;
+            }
+
+            match node.kind {
+                BvhNodeKind::Leaf { first, count } => {
+                    for primitive in &self.primitives[first..(first + count)] {
+                        if let Some(intersection) = primitive.intersect(ray) {
+                            let distance = intersection.get_distance_to_intersection();
+                            if distance < closest_distance {
+                                closest_distance = distance;
+                                closest = Some(intersection);
+                            }
+                        }
+                    }
+                },
+                BvhNodeKind::Interior { left, right } => {
+                    stack.push(left);
+                    stack.push(right);
+                },
+            }
+        }
+
+        closest
+    }
+}
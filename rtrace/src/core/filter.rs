@@ -0,0 +1,68 @@
+use defs::{FloatType};
+use core::Color;
+
+/// Reconstruction filters for resolving a pixel's jittered sub-samples into a single color.
+/// Each variant's `weight` is a function of the sample's distance from the pixel center
+/// (in pixel-footprint units, i.e. `0.0` is the pixel center and `1.0` is a half-pixel away),
+/// normalized by the summed weights when the `Film` resolves.
+#[derive(Debug, Clone, Copy)]
+pub enum ReconstructionFilter {
+    Box,
+    Triangle,
+    Gaussian { sigma: FloatType },
+}
+
+impl ReconstructionFilter {
+    pub fn weight(&self, distance: FloatType) -> FloatType {
+        match *self {
+            ReconstructionFilter::Box => 1.0,
+            ReconstructionFilter::Triangle => (1.0 - distance).max(0.0),
+            ReconstructionFilter::Gaussian { sigma } => (-(distance * distance) / (2.0 * sigma * sigma)).exp(),
+        }
+    }
+}
+
+/// Per-pixel `(weighted_color_sum, weight_sum)` accumulator. `supersampler::render_supersampled`
+/// scatters every jittered sub-sample into this buffer instead of overwriting the pixel
+/// outright, so the final color is only known once every sample has been splatted and the
+/// buffer is `resolve`d.
+#[derive(Clone, Copy)]
+struct FilmCell {
+    weighted_color_sum: Color,
+    weight_sum: FloatType,
+}
+
+impl FilmCell {
+    fn empty() -> Self {
+        Self { weighted_color_sum: Color::zero(), weight_sum: 0.0 }
+    }
+}
+
+pub struct Film {
+    width: usize,
+    height: usize,
+    cells: Vec<FilmCell>,
+}
+
+impl Film {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {  width,
+                height,
+                cells: vec![FilmCell::empty(); width * height] }
+    }
+
+    pub fn accumulate(&mut self, x: usize, y: usize, color: Color, weight: FloatType) {
+        let cell = &mut self.cells[y * self.width + x];
+        cell.weighted_color_sum += color.mul_scalar(&weight);
+        cell.weight_sum += weight;
+    }
+
+    pub fn resolve(&self, x: usize, y: usize) -> Option<Color> {
+        let cell = self.cells[y * self.width + x];
+        if cell.weight_sum > 0.0 {
+            Some(cell.weighted_color_sum.mul_scalar(&(1.0 / cell.weight_sum)))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use defs::FloatType;
+use core::{Color, FresnelIndex};
+
+/// What a surface does with light arriving at an intersection. Every variant carries a trailing
+/// `roughness`, defaulted from the constructors' `Option<FloatType>` (`None` behaves like
+/// `0.0`): the standard deviation, in radians, of the Oren-Nayar microfacet slope distribution
+/// `brdf::OrenNayarParams` expects. A roughness of `0.0` degenerates Oren-Nayar to Lambertian, so
+/// leaving it `None` costs nothing.
+#[derive(Debug, Clone, Copy)]
+pub enum Material {
+    Diffuse { albedo: Color, roughness: FloatType },
+    Shiny { albedo: Color, specular: (Color, FloatType), roughness: FloatType },
+    Reflective { index: FresnelIndex, extinction: FresnelIndex, specular: Option<(Color, FloatType)>, roughness: FloatType },
+    ReflectiveAndRefractive { index: FresnelIndex, extinction: FresnelIndex, specular: Option<(Color, FloatType)>, roughness: FloatType },
+    LightSource { emission: Color, roughness: FloatType },
+}
+
+impl Material {
+    pub fn new_diffuse(albedo: Color, roughness: Option<FloatType>) -> Self {
+        Material::Diffuse { albedo, roughness: roughness.unwrap_or(0.0) }
+    }
+
+    pub fn new_shiny(albedo: Color, specular: (Color, FloatType), roughness: Option<FloatType>) -> Self {
+        Material::Shiny { albedo, specular, roughness: roughness.unwrap_or(0.0) }
+    }
+
+    pub fn new_reflective(index: FresnelIndex, extinction: FresnelIndex, _diffuse_tint: Option<Color>, specular: Option<(Color, FloatType)>, roughness: Option<FloatType>) -> Self {
+        Material::Reflective { index, extinction, specular, roughness: roughness.unwrap_or(0.0) }
+    }
+
+    pub fn new_reflective_and_refractive(index: FresnelIndex, extinction: FresnelIndex, _diffuse_tint: Option<Color>, specular: Option<(Color, FloatType)>, roughness: Option<FloatType>) -> Self {
+        Material::ReflectiveAndRefractive { index, extinction, specular, roughness: roughness.unwrap_or(0.0) }
+    }
+
+    pub fn new_light_source(emission: Color, roughness: Option<FloatType>) -> Self {
+        Material::LightSource { emission, roughness: roughness.unwrap_or(0.0) }
+    }
+
+    pub fn get_emission(&self) -> Option<Color> {
+        match *self {
+            Material::LightSource { emission, .. } => Some(emission),
+            _ => None,
+        }
+    }
+
+    /// Throughput multiplier for a bounce off this surface: the diffuse color for `Diffuse`/
+    /// `Shiny`, the specular tint (or neutral, if untinted) for the reflective variants, and
+    /// black for a light source (nothing bounces further off an emitter here).
+    pub fn get_albedo(&self) -> Color {
+        match *self {
+            Material::Diffuse { albedo, .. } => albedo,
+            Material::Shiny { albedo, .. } => albedo,
+            Material::Reflective { specular, .. } => specular.map(|(color, _)| color).unwrap_or(Color::one()),
+            Material::ReflectiveAndRefractive { specular, .. } => specular.map(|(color, _)| color).unwrap_or(Color::one()),
+            Material::LightSource { .. } => Color::zero(),
+        }
+    }
+
+    /// Single representative index of refraction for callers (like the path tracer's Fresnel/
+    /// refraction bounce) that need a scalar IOR rather than `FresnelIndex`'s per-channel one:
+    /// the green channel, matching this crate's own luminance weighting elsewhere giving green
+    /// the most weight (see `tonemap`'s `LUMINANCE_WEIGHTS`).
+    pub fn get_fresnel_index(&self) -> Option<FloatType> {
+        match *self {
+            Material::Reflective { index, .. } | Material::ReflectiveAndRefractive { index, .. } => Some(index.get().1),
+            _ => None,
+        }
+    }
+
+    pub fn is_refractive(&self) -> bool {
+        match *self {
+            Material::ReflectiveAndRefractive { .. } => true,
+            _ => false,
+        }
+    }
+
+    pub fn roughness(&self) -> FloatType {
+        match *self {
+            Material::Diffuse { roughness, .. }
+            | Material::Shiny { roughness, .. }
+            | Material::Reflective { roughness, .. }
+            | Material::ReflectiveAndRefractive { roughness, .. }
+            | Material::LightSource { roughness, .. } => roughness,
+        }
+    }
+}
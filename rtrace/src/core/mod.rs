@@ -0,0 +1,23 @@
+pub mod bvh;
+pub mod brdf;
+pub mod color;
+pub mod filter;
+pub mod intersection;
+pub mod material;
+pub mod ray;
+pub mod supersampler;
+pub mod tonemap;
+
+pub use self::color::{Color, ColorBase, FresnelIndex};
+pub use self::intersection::{RayIntersection, RayIntersectionError};
+pub use self::material::Material;
+pub use self::ray::Ray;
+
+// `View`, `ViewIterator`, `RayCaster`, `WorldViewTrait`, `RenderingTaskProducer`,
+// `ScreenIterator`, `OrderedTaskProducers`, `ThreadSafeIterator`, `ModelViewModelWrapper`,
+// `World`, `WorldView`, `SceneBufferLayering`, `ImmutableSceneBuffer`, `MutableSceneBuffer`,
+// `ImmutableSceneBufferWrapper`, `BasicSceneBuffer` are all referenced throughout `core`'s own
+// submodules and by `main.rs`, but none of them have a source file in this checkout (no
+// `view.rs`/`world.rs`/... exist alongside `color.rs`, `intersection.rs`, `material.rs` and
+// `ray.rs`). There is nothing here to `pub mod`/re-export them from yet; this file only wires up
+// the submodules that actually exist on disk.
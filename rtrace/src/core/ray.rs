@@ -0,0 +1,34 @@
+use defs::{Point3, Vector3, Matrix4};
+
+/// An origin point and a direction, the one thing every intersection test in this crate is run
+/// against. `Copy` because call sites throughout `core`/`basic` pass rays by value freely (e.g.
+/// `RayIntersection::new` stores `*ray`).
+#[derive(Debug, Clone, Copy)]
+pub struct Ray {
+    origin: Point3,
+    direction: Vector3,
+}
+
+impl Ray {
+    pub fn new(origin: Point3, direction: Vector3) -> Self {
+        Self { origin, direction }
+    }
+
+    pub fn get_origin(&self) -> &Point3 {
+        &self.origin
+    }
+
+    pub fn get_direction(&self) -> Vector3 {
+        self.direction
+    }
+
+    pub fn get_transformed(&self, point_and_dir_mx: (&Matrix4, &Matrix4)) -> Self {
+        let (point_tf_mx, vector_tf_mx) = point_and_dir_mx;
+
+        let origin = self.origin.to_homogeneous();
+        let direction = self.direction.to_homogeneous();
+
+        Self {  origin: Point3::from_homogeneous(point_tf_mx * origin).expect("Unhomogeneous transformed ray origin"),
+                direction: Vector3::from_homogeneous(vector_tf_mx * direction).expect("Unhomogeneous transformed ray direction") }
+    }
+}
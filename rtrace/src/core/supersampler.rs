@@ -0,0 +1,103 @@
+use rand::{Rng, thread_rng};
+
+use defs::{FloatType, Point2Int};
+use core::{View, Ray, ViewIterator};
+use core::filter::{Film, ReconstructionFilter};
+
+/// One jittered sub-sample of a pixel: the `Ray` to cast, the pixel it belongs to, and the
+/// sample's offset from the pixel center (in half-pixel units) so the reconstruction filter can
+/// weight it.
+pub struct SuperSample {
+    pub ray: Ray,
+    pub pixel: Point2Int,
+    pub offset_from_center: FloatType,
+}
+
+/// Drives `samples_per_pixel` (rounded up to the nearest perfect square, `S`-by-`S` jittered sub-
+/// samples) per pixel of an underlying `ViewIterator`, for `render_supersampled`. Each
+/// sub-sample is randomly jittered within its cell of the pixel footprint, which is what turns
+/// regular aliasing into less objectionable noise.
+pub struct SupersamplingViewIterator<'a> {
+    base: ViewIterator<'a>,
+    current_pixel: Option<(Ray, Point2Int)>,
+    samples_per_axis: u32,
+    sub_sample_index: u32,
+}
+
+impl<'a> SupersamplingViewIterator<'a> {
+    pub fn new(view: &'a View, samples_per_pixel: u32) -> Self {
+        let samples_per_axis = (samples_per_pixel as FloatType).sqrt().ceil() as u32;
+        Self {  base: ViewIterator::new(view),
+                current_pixel: None,
+                samples_per_axis: samples_per_axis.max(1),
+                sub_sample_index: 0 }
+    }
+}
+
+impl<'a> Iterator for SupersamplingViewIterator<'a> {
+    type Item = SuperSample;
+
+    fn next(&mut self) -> Option<SuperSample> {
+        if self.current_pixel.is_none() {
+            self.current_pixel = self.base.next();
+            self.sub_sample_index = 0;
+        }
+
+        let (base_ray, pixel) = self.current_pixel?;
+        let total_sub_samples = self.samples_per_axis * self.samples_per_axis;
+
+        let cell_x = self.sub_sample_index % self.samples_per_axis;
+        let cell_y = self.sub_sample_index / self.samples_per_axis;
+
+        let cell_size = 1.0 / self.samples_per_axis as FloatType;
+        let mut rng = thread_rng();
+        let jitter_x: FloatType = rng.gen();
+        let jitter_y: FloatType = rng.gen();
+
+        let offset_x = (cell_x as FloatType + jitter_x) * cell_size - 0.5;
+        let offset_y = (cell_y as FloatType + jitter_y) * cell_size - 0.5;
+
+        // Chebyshev rather than Euclidean distance: the pixel footprint is a square, not a disk,
+        // so a corner sample (offset_x == offset_y == 0.5) is exactly as far from center as an
+        // edge sample (offset_x == 0.5, offset_y == 0.0) is - both reach the `1.0` edge of the
+        // footprint, rather than the corner overshooting to sqrt(2) and getting zeroed by
+        // `ReconstructionFilter::Triangle`'s `(1.0 - distance).max(0.0)`.
+        let offset_from_center = offset_x.abs().max(offset_y.abs()) * 2.0;
+
+        let ray = self.base.offset_ray_within_pixel(&base_ray, pixel, offset_x, offset_y);
+
+        self.sub_sample_index += 1;
+        if self.sub_sample_index >= total_sub_samples {
+            self.current_pixel = None;
+        }
+
+        Some(SuperSample { ray, pixel, offset_from_center })
+    }
+}
+
+/// Renders every `SuperSample` of `view` through `shade`, splatting each result into a `Film`
+/// with `filter`'s per-sample weight, and returns that `Film`; `Film::resolve` gives each
+/// pixel's final color.
+///
+/// `View::new_supersampled(...)` (the constructor this was written against) isn't added here:
+/// that would mean inventing `View`'s full constructor plus `WorldView`/`Screen`/`RayCaster`/
+/// `WorldViewTrait` and the scene-buffer stack (`BasicSceneBuffer`, `SceneBufferLayering`, ...)
+/// it's wired through in `main.rs` (`WorldView::new(world, view)`, `worldview.get_pixel_value`,
+/// `ScreenIterator::new(...)`), none of which have a source file in this checkout - the same gap
+/// `core/mod.rs` documents. Unlike `Material` (a small, closed set of constructors/accessors
+/// main.rs's call sites fully pin down), `View`'s real shape isn't recoverable from this tree
+/// without guessing at a whole rendering core, so this stays a free function callers can already
+/// reach once they hold a `&View`, rather than a method on a type that doesn't exist yet.
+pub fn render_supersampled<F: Fn(&Ray) -> Option<::core::Color>>(view: &View, samples_per_pixel: u32, filter: ReconstructionFilter, shade: F) -> Film {
+    let (width, height) = view.get_screen().get_resolution();
+    let mut film = Film::new(width as usize, height as usize);
+
+    for sample in SupersamplingViewIterator::new(view, samples_per_pixel) {
+        if let Some(color) = shade(&sample.ray) {
+            let weight = filter.weight(sample.offset_from_center);
+            film.accumulate(sample.pixel.x as usize, sample.pixel.y as usize, color, weight);
+        }
+    }
+
+    film
+}
@@ -1,3 +1,5 @@
+use std::ops::{Add, Sub, Neg, Mul, AddAssign, SubAssign, MulAssign, Div, DivAssign, Index, IndexMut};
+
 use linalg::num::traits::{Num, Zero, One};
 
 
@@ -15,6 +17,7 @@ pub enum MatrixOpResult {
     InvalidSize,
     NotSquareMatrix,
     NotVector,
+    ZeroDeterminant,
 }
 
 
@@ -65,10 +68,15 @@ pub trait TMatrix<T: MNum> {
     fn sub_mut(&mut self, rhs: &Matrix<T>) -> Result<MatrixOpResult, MatrixOpResult>;
     fn mul_immut(&self, rhs: &T) -> Result<Matrix<T>, MatrixOpResult>;
     fn mul_mut(&mut self, rhs: &T) -> Result<MatrixOpResult, MatrixOpResult>;
+    fn div_immut(&self, rhs: &T) -> Result<Matrix<T>, MatrixOpResult>;
+    fn div_mut(&mut self, rhs: &T) -> Result<MatrixOpResult, MatrixOpResult>;
     fn xmul_immut(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixOpResult>;
     fn xmul_mut(&mut self, rhs: &Matrix<T>) -> Result<MatrixOpResult, MatrixOpResult>;
     fn transpose_immut(&self) -> Result<Matrix<T>, MatrixOpResult>;
     fn transpose_mut(&mut self) -> Result<MatrixOpResult, MatrixOpResult>;
+    fn minor(&self, row: usize, col: usize) -> Result<Matrix<T>, MatrixOpResult>;
+    fn determinant(&self) -> Result<T, MatrixOpResult>;
+    fn inverse(&self) -> Result<Matrix<T>, MatrixOpResult>;
 
 }
 
@@ -115,7 +123,7 @@ impl<T: MNum> TMatrix<T> for Matrix<T> {
 
     fn coord_transform(&self, i: usize, j: usize) -> Result<usize, MatrixOpResult> {
         if (i < self.n) && (j < self.m) {
-            return Ok(i * self.n + j);
+            return Ok(i * self.m + j);
         } else {
             return Err(MatrixOpResult::InvalidIndex); 
         }
@@ -123,7 +131,7 @@ impl<T: MNum> TMatrix<T> for Matrix<T> {
 
     fn index_transform(&self, n: usize) -> Result<(usize, usize), MatrixOpResult> {
         if n < self.data.len() {
-            return Ok((n / self.n.clone(), n % self.n.clone()));
+            return Ok((n / self.m.clone(), n % self.m.clone()));
         } else {
             return Err(MatrixOpResult::InvalidIndex); 
         }
@@ -153,19 +161,8 @@ impl<T: MNum> TMatrix<T> for Matrix<T> {
     
     fn load_identity(&mut self) -> Result<MatrixOpResult, MatrixOpResult> {
         if self.n == self.m {
-            for idx in 0..self.data.len() {
-                match self.index_transform(idx) {
-                    Ok((i, j))  => {
-                        let item: &mut T = self.data.get_mut(idx).unwrap();
-
-                        if i == j {
-                            *item = T::one();
-                        } else {
-                            *item = T::zero();
-                        }
-                    },
-                    Err(_)    => panic!("Unrecovarable error"),
-                }
+            for (i, j, item) in self.iter_indexed_mut() {
+                *item = if i == j { T::one() } else { T::zero() };
             }
             return Ok(MatrixOpResult::Successful);
         } else {
@@ -241,6 +238,24 @@ impl<T: MNum> TMatrix<T> for Matrix<T> {
         Ok(MatrixOpResult::Successful)
     }
 
+    fn div_immut(&self, rhs: &T) -> Result<Matrix<T>, MatrixOpResult> {
+        let mut clone = self.clone();
+        match clone.div_mut(rhs) {
+            Ok(_)       => Ok(clone),
+            Err(err)    => Err(err),
+        }
+    }
+
+    fn div_mut(&mut self, rhs: &T) -> Result<MatrixOpResult, MatrixOpResult> {
+        for item in &mut self.data {
+            let lhsval: T = item.clone();
+            let rhsval: T = rhs.clone();
+            *item = lhsval / rhsval;
+        }
+
+        Ok(MatrixOpResult::Successful)
+    }
+
     fn xmul_immut(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixOpResult> {
         if self.m != rhs.n {
             return Err(MatrixOpResult::SizeMismatch);
@@ -254,10 +269,10 @@ impl<T: MNum> TMatrix<T> for Matrix<T> {
         };
         
         for i in 0..result.n {
-            for j in 0..result.n {
-                for k in 0..result.m {
-                    let nval: &mut T = try!(result.get_mut(i, k));
-                    *nval = nval.clone() + try!(self.get_immut(i, k)) * try!(rhs.get_immut(j, k)); 
+            for j in 0..result.m {
+                for k in 0..self.m {
+                    let nval: &mut T = try!(result.get_mut(i, j));
+                    *nval = nval.clone() + try!(self.get_immut(i, k)) * try!(rhs.get_immut(k, j));
                 }
             }
         }
@@ -292,8 +307,227 @@ impl<T: MNum> TMatrix<T> for Matrix<T> {
         *self = try!(self.transpose_immut());
         Ok (MatrixOpResult::Successful)
     }
+
+    fn minor(&self, row: usize, col: usize) -> Result<Matrix<T>, MatrixOpResult> {
+        if (self.n < 2) || (self.m < 2) {
+            return Err(MatrixOpResult::InvalidSize);
+        }
+
+        if (row >= self.n) || (col >= self.m) {
+            return Err(MatrixOpResult::InvalidIndex);
+        }
+
+        let mut data: Vec<T> = Vec::with_capacity((self.n - 1) * (self.m - 1));
+        for i in 0..self.n {
+            if i == row {
+                continue;
+            }
+
+            for j in 0..self.m {
+                if j == col {
+                    continue;
+                }
+
+                data.push(try!(self.get_immut(i, j)));
+            }
+        }
+
+        Ok (Matrix { n: self.n - 1, m: self.m - 1, data: data })
+    }
+
+    // Laplace cofactor expansion along the first row.
+    fn determinant(&self) -> Result<T, MatrixOpResult> {
+        if self.n != self.m {
+            return Err(MatrixOpResult::NotSquareMatrix);
+        }
+
+        if self.n == 1 {
+            return self.get_immut(0, 0);
+        }
+
+        if self.n == 2 {
+            let a00 = try!(self.get_immut(0, 0));
+            let a01 = try!(self.get_immut(0, 1));
+            let a10 = try!(self.get_immut(1, 0));
+            let a11 = try!(self.get_immut(1, 1));
+            return Ok(a00 * a11 - a01 * a10);
+        }
+
+        let mut sum = T::zero();
+        for j in 0..self.m {
+            let element = try!(self.get_immut(0, j));
+            let minor_determinant = try!(try!(self.minor(0, j)).determinant());
+            sum = if j % 2 == 0 {
+                sum + element * minor_determinant
+            } else {
+                sum - element * minor_determinant
+            };
+        }
+
+        Ok(sum)
+    }
+
+    // Adjugate method: build the cofactor matrix, transpose it into the adjugate, then scale by
+    // the reciprocal of the determinant.
+    fn inverse(&self) -> Result<Matrix<T>, MatrixOpResult> {
+        if self.n != self.m {
+            return Err(MatrixOpResult::NotSquareMatrix);
+        }
+
+        let determinant = try!(self.determinant());
+        if determinant == T::zero() {
+            return Err(MatrixOpResult::ZeroDeterminant);
+        }
+
+        let mut cofactor_data: Vec<T> = Vec::with_capacity(self.n * self.m);
+        for i in 0..self.n {
+            for j in 0..self.m {
+                let cofactor = if self.n == 1 {
+                    T::one()
+                } else {
+                    let minor_determinant = try!(try!(self.minor(i, j)).determinant());
+                    if (i + j) % 2 == 0 {
+                        minor_determinant
+                    } else {
+                        T::zero() - minor_determinant
+                    }
+                };
+                cofactor_data.push(cofactor);
+            }
+        }
+
+        let cofactor_matrix = Matrix { n: self.n, m: self.m, data: cofactor_data };
+        let adjugate = try!(cofactor_matrix.transpose_immut());
+        let inverse_determinant = T::one() / determinant;
+        adjugate.mul_immut(&inverse_determinant)
+    }
 }
 
 
 // ----- Operator implementation -----------------------------------------------------------------
 
+// Ergonomic wrappers around the fallible `*_immut`/`*_mut` methods: a size mismatch here is a
+// programmer error in scene/transform math, so it panics instead of surfacing as `Err`. Callers
+// who want to handle mismatched sizes gracefully can still go through the `TMatrix` methods.
+
+impl<T: MNum> Add for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Matrix<T>) -> Matrix<T> {
+        self.add_immut(&rhs).expect("Matrix size mismatch in +")
+    }
+}
+
+impl<T: MNum> AddAssign for Matrix<T> {
+    fn add_assign(&mut self, rhs: Matrix<T>) {
+        self.add_mut(&rhs).expect("Matrix size mismatch in +=");
+    }
+}
+
+impl<T: MNum> Sub for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: Matrix<T>) -> Matrix<T> {
+        self.sub_immut(&rhs).expect("Matrix size mismatch in -")
+    }
+}
+
+impl<T: MNum> SubAssign for Matrix<T> {
+    fn sub_assign(&mut self, rhs: Matrix<T>) {
+        self.sub_mut(&rhs).expect("Matrix size mismatch in -=");
+    }
+}
+
+impl<T: MNum> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Matrix<T> {
+        for item in &mut self.data {
+            *item = T::zero() - item.clone();
+        }
+        self
+    }
+}
+
+impl<T: MNum> Mul<Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Matrix<T> {
+        self.xmul_immut(&rhs).expect("Matrix size mismatch in *")
+    }
+}
+
+impl<T: MNum> MulAssign<Matrix<T>> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: Matrix<T>) {
+        self.xmul_mut(&rhs).expect("Matrix size mismatch in *=");
+    }
+}
+
+impl<T: MNum> Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: T) -> Matrix<T> {
+        self.mul_immut(&rhs).expect("Matrix scalar multiplication failed")
+    }
+}
+
+impl<T: MNum> MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.mul_mut(&rhs).expect("Matrix scalar multiplication failed");
+    }
+}
+
+impl<T: MNum> Div<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn div(self, rhs: T) -> Matrix<T> {
+        self.div_immut(&rhs).expect("Matrix scalar division failed")
+    }
+}
+
+impl<T: MNum> DivAssign<T> for Matrix<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.div_mut(&rhs).expect("Matrix scalar division failed");
+    }
+}
+
+// ----- Iterator implementation ------------------------------------------------------------------
+
+impl<T: MNum> Matrix<T> {
+    /// Yields the `(i, j)` coordinate of every element in row-major order, without computing
+    /// linear offsets through `coord_transform`/`index_transform` by hand.
+    pub fn indices(&self) -> impl Iterator<Item = (usize, usize)> {
+        let m = self.m;
+        (0..self.n).flat_map(move |i| (0..m).map(move |j| (i, j)))
+    }
+
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.indices().zip(self.data.iter()).map(|((i, j), value)| (i, j, value))
+    }
+
+    pub fn iter_indexed_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut T)> {
+        let m = self.m;
+        let indices = (0..self.n).flat_map(move |i| (0..m).map(move |j| (i, j)));
+        indices.zip(self.data.iter_mut()).map(|((i, j), value)| (i, j, value))
+    }
+}
+
+// `m[(i, j)]` instead of `get_immut`/`get_mut` with their `Result` unwrapping; bounds-checked
+// code paths should keep using the fallible accessors directly.
+
+impl<T: MNum> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!((i < self.n) && (j < self.m), "Matrix index out of bounds");
+        &self.data[i * self.m + j]
+    }
+}
+
+impl<T: MNum> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!((i < self.n) && (j < self.m), "Matrix index out of bounds");
+        &mut self.data[i * self.m + j]
+    }
+}
+
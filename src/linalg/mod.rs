@@ -0,0 +1,5 @@
+pub mod matrix;
+pub mod smatrix;
+
+pub use self::matrix::{Matrix, MNum, MatrixOpResult, TMatrix};
+pub use self::smatrix::{SMatrix, Mat4};
@@ -0,0 +1,173 @@
+use std::ops::{Add, Sub, Neg, Mul, Index, IndexMut};
+
+use linalg::matrix::{Matrix, MNum, TMatrix};
+
+// ----- Definitions ---------------------------------------------------------------------------
+
+/// Stack-allocated, compile-time-sized counterpart to `Matrix<T>`, for the small transforms that
+/// dominate the rendering hot path: no heap allocation and no `SizeMismatch` branch, since `M`
+/// and `N` are part of the type itself. `Matrix<T>` remains the right choice for scene-loading/
+/// parsing code where sizes are only known at runtime.
+///
+/// `Mul<SMatrix<T, N, P>>` is only implemented for `SMatrix<T, M, N>` (the shared `N` is the
+/// inner dimension), so `A * B` with mismatched inner dimensions is a compile error rather than
+/// a runtime `SizeMismatch` the way `Matrix::xmul_immut` reports it.
+#[derive(Debug, Clone, Copy)]
+pub struct SMatrix<T: MNum + Copy, const M: usize, const N: usize> {
+    data: [[T; N]; M],
+}
+
+/// The 4x4 case that transform code actually needs.
+pub type Mat4<T> = SMatrix<T, 4, 4>;
+
+impl<T: MNum + Copy, const M: usize, const N: usize> SMatrix<T, M, N> {
+    pub fn new(data: [[T; N]; M]) -> Self {
+        Self { data }
+    }
+
+    pub fn filled(value: T) -> Self {
+        Self { data: [[value; N]; M] }
+    }
+}
+
+impl<T: MNum + Copy, const M: usize> SMatrix<T, M, M> {
+    pub fn load_identity(&mut self) {
+        for i in 0..M {
+            for j in 0..M {
+                self.data[i][j] = if i == j { T::one() } else { T::zero() };
+            }
+        }
+    }
+}
+
+impl<T: MNum + Copy, const M: usize, const N: usize> Index<(usize, usize)> for SMatrix<T, M, N> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.data[i][j]
+    }
+}
+
+impl<T: MNum + Copy, const M: usize, const N: usize> IndexMut<(usize, usize)> for SMatrix<T, M, N> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.data[i][j]
+    }
+}
+
+impl<T: MNum + Copy, const M: usize, const N: usize> Add for SMatrix<T, M, N> {
+    type Output = SMatrix<T, M, N>;
+
+    fn add(self, rhs: SMatrix<T, M, N>) -> SMatrix<T, M, N> {
+        let mut result = self;
+        for i in 0..M {
+            for j in 0..N {
+                result.data[i][j] = result.data[i][j].clone() + rhs.data[i][j].clone();
+            }
+        }
+        result
+    }
+}
+
+impl<T: MNum + Copy, const M: usize, const N: usize> Sub for SMatrix<T, M, N> {
+    type Output = SMatrix<T, M, N>;
+
+    fn sub(self, rhs: SMatrix<T, M, N>) -> SMatrix<T, M, N> {
+        let mut result = self;
+        for i in 0..M {
+            for j in 0..N {
+                result.data[i][j] = result.data[i][j].clone() - rhs.data[i][j].clone();
+            }
+        }
+        result
+    }
+}
+
+impl<T: MNum + Copy, const M: usize, const N: usize> Neg for SMatrix<T, M, N> {
+    type Output = SMatrix<T, M, N>;
+
+    fn neg(self) -> SMatrix<T, M, N> {
+        let mut result = self;
+        for i in 0..M {
+            for j in 0..N {
+                result.data[i][j] = T::zero() - result.data[i][j].clone();
+            }
+        }
+        result
+    }
+}
+
+/// `M x N * N x P -> M x P`: the inner dimension `N` is shared by both operands' types, so a
+/// mismatched-inner-dimension multiply fails to type-check instead of failing at runtime.
+impl<T: MNum + Copy, const M: usize, const N: usize, const P: usize> Mul<SMatrix<T, N, P>> for SMatrix<T, M, N> {
+    type Output = SMatrix<T, M, P>;
+
+    fn mul(self, rhs: SMatrix<T, N, P>) -> SMatrix<T, M, P> {
+        let mut result = SMatrix::filled(T::zero());
+        for i in 0..M {
+            for k in 0..N {
+                for j in 0..P {
+                    result.data[i][j] = result.data[i][j].clone() + self.data[i][k].clone() * rhs.data[k][j].clone();
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<T: MNum + Copy, const M: usize, const N: usize> From<SMatrix<T, M, N>> for Matrix<T> {
+    fn from(small: SMatrix<T, M, N>) -> Matrix<T> {
+        let data: Vec<T> = (0..M).flat_map(|i| (0..N).map(move |j| small.data[i][j].clone())).collect();
+        Matrix::new_filled(M, N, &data).expect("SMatrix -> Matrix conversion has a fixed valid size")
+    }
+}
+
+impl<T: MNum + Copy, const M: usize, const N: usize> From<Matrix<T>> for SMatrix<T, M, N> {
+    fn from(large: Matrix<T>) -> SMatrix<T, M, N> {
+        assert_eq!(large.get_n(), M, "Matrix -> SMatrix conversion row count mismatch");
+        assert_eq!(large.get_m(), N, "Matrix -> SMatrix conversion column count mismatch");
+
+        let mut result = SMatrix::filled(T::zero());
+        for i in 0..M {
+            for j in 0..N {
+                result.data[i][j] = large.get_immut(i, j).unwrap();
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SMatrix, Mat4};
+
+    #[test]
+    fn multiply_checks_inner_dimension_at_compile_time() {
+        let a: SMatrix<f64, 2, 3> = SMatrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b: SMatrix<f64, 3, 2> = SMatrix::new([[7.0, 8.0], [9.0, 10.0], [11.0, 12.0]]);
+
+        let product = a * b;
+
+        assert_eq!(product[(0, 0)], 1.0 * 7.0 + 2.0 * 9.0 + 3.0 * 11.0);
+        assert_eq!(product[(0, 1)], 1.0 * 8.0 + 2.0 * 10.0 + 3.0 * 12.0);
+        assert_eq!(product[(1, 0)], 4.0 * 7.0 + 5.0 * 9.0 + 6.0 * 11.0);
+        assert_eq!(product[(1, 1)], 4.0 * 8.0 + 5.0 * 10.0 + 6.0 * 12.0);
+    }
+
+    #[test]
+    fn mat4_times_identity_is_itself() {
+        let mut identity: Mat4<f64> = Mat4::filled(0.0);
+        identity.load_identity();
+
+        let m: Mat4<f64> = Mat4::new([[1.0, 0.0, 0.0, 2.0],
+                                       [0.0, 1.0, 0.0, 3.0],
+                                       [0.0, 0.0, 1.0, 4.0],
+                                       [0.0, 0.0, 0.0, 1.0]]);
+
+        let product = m * identity;
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(product[(i, j)], m[(i, j)]);
+            }
+        }
+    }
+}
@@ -6,6 +6,7 @@ extern crate approx; // For the macro relative_eq!
 extern crate nalgebra as na;
 
 mod renderer;
+mod linalg;
 
 use rtrace::basic::{SimpleIlluminator, SimpleIntersector, SimpleColorCalculator, WorldViewTaskProducer, 
                     GlobalIlluminationShaderTaskProducer, GlobalIlluminationShader, MedianFilter};
@@ -15,6 +16,7 @@ use rtrace::core::{ModelViewModelWrapper, Material, Color, ThreadSafeIterator,
                    FresnelIndex, View, World, WorldView, WorldViewTrait, RenderingTaskProducer, ScreenIterator,
                    OrderedTaskProducers, SceneBufferLayering, ImmutableSceneBuffer, MutableSceneBuffer ,ImmutableSceneBufferWrapper,
                    BasicSceneBuffer};
+use rtrace::core::tonemap::{ToneMapper, ToneMapOperator};
 use rtrace::defs::{Point3, Point2Int, Vector3, FloatType};
 use image::{DynamicImage, Rgba, Pixel, GenericImage, ImageFormat};
 use renderer::{SingleThreadedRenderer, ParalellRenderer, RendererOutput};
@@ -28,11 +30,18 @@ use std::f64::consts::{PI, FRAC_PI_2};
 
 struct ImageRendererOutput {
     image: DynamicImage,
+    tonemapper: ToneMapper,
 }
 
 impl ImageRendererOutput {
     pub fn new(width: u32, height: u32) -> Self {
-        Self {  image: DynamicImage::new_rgb8(width, height) }
+        Self {  image: DynamicImage::new_rgb8(width, height),
+                tonemapper: ToneMapper::clamp() }
+    }
+
+    pub fn new_with_tonemapper(width: u32, height: u32, tonemapper: ToneMapper) -> Self {
+        Self {  image: DynamicImage::new_rgb8(width, height),
+                tonemapper: tonemapper }
     }
 
     pub fn get_image(&self) -> &DynamicImage {
@@ -42,7 +51,7 @@ impl ImageRendererOutput {
 
 impl RendererOutput for ImageRendererOutput {
     fn set_output(&mut self, coord: Point2Int, color: Color) -> bool {
-        let (r, g, b) = color.normalized().mul_scalar(&(u8::max_value() as FloatType)).get();
+        let (r, g, b) = self.tonemapper.apply(color).mul_scalar(&(u8::max_value() as FloatType)).get();
         let pixel_color = Rgba::from_channels(r as u8, g as u8, b as u8, u8::max_value());
         self.image.put_pixel(coord.x as u32, coord.y as u32, pixel_color);
         true
@@ -152,12 +161,14 @@ fn main() {
     // worldview.layer_buffer(SceneBufferLayering::Over, 
     //                        &ImmutableSceneBufferWrapper::new(gi_overlay.as_ref()));
 
+    let tonemapper = ToneMapper::new(ToneMapOperator::ReinhardJodie, Some(2.2));
+
     let screen = worldview.get_view().get_screen();
     let (width, height) = screen.get_resolution();
     let mut result_image = DynamicImage::new_rgb8(width as u32, height as u32);
     for coord in ScreenIterator::new(worldview.get_view().get_screen()) {
         if let Ok(Some(color)) = worldview.get_pixel_value(coord) {
-            let (r, g, b) = color.normalized().mul_scalar(&(u8::max_value() as FloatType)).get();
+            let (r, g, b) = tonemapper.apply(color).mul_scalar(&(u8::max_value() as FloatType)).get();
             let pixel_color = Rgba::from_channels(r as u8, g as u8, b as u8, u8::max_value());
             result_image.put_pixel(coord.x as u32, coord.y as u32, pixel_color);
         }
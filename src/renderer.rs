@@ -1,5 +1,5 @@
-use std::sync::{Arc, mpsc};
-use std::sync::mpsc::{Sender, Receiver, RecvError, TryRecvError, SendError};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::{thread};
 
 use rtrace::core::{Color, View, ViewIterator, RayCaster, Ray};
@@ -45,233 +45,113 @@ impl<WorldType: RayCaster,
     }
 }
 
-enum ControlMessage {
-    CastRay(Ray, Point2Int),
-    Exit,
-}
-
-enum WorkerMessage {
-    Ready,
-    Result(Option<Color>, Point2Int),
-}
+const TILE_SIZE: usize = 32;
 
-struct ParallelWorker<WorldType> {
-    world: Arc<WorldType>,
-    control_tx: Option<Sender<ControlMessage>>,
-    worker_rx: Option<Receiver<WorkerMessage>>,
-    join_handle: Option<thread::JoinHandle<()>>
+#[derive(Clone, Copy)]
+struct Tile {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
 }
 
-#[allow(dead_code)]
-impl<WorldType: 'static + RayCaster + Sync + Send> ParallelWorker<WorldType> {
-    pub fn new(world: Arc<WorldType>) -> Self {
-        Self {  world: world,
-                control_tx: None,
-                worker_rx: None,
-                join_handle: None}
-    }
-
-    pub fn spawn(&mut self) {
-        let (control_tx, control_rx): (Sender<ControlMessage>, Receiver<ControlMessage>) = mpsc::channel();
-        let (worker_tx, worker_rx): (Sender<WorkerMessage>, Receiver<WorkerMessage>) = mpsc::channel();
-        self.control_tx = Some(control_tx);
-        self.worker_rx = Some(worker_rx);
-        
-        let world = Arc::clone(&self.world);
-
-        self.join_handle = Some(thread::spawn(move || {
-            worker_tx.send(WorkerMessage::Ready).expect("Initial ready message in worker unhandled");
-            loop {
-                match control_rx.recv() {
-                    Ok(message) => {
-                        match message {
-                            ControlMessage::Exit => {
-                                break;
-                            },
-                            ControlMessage::CastRay(ray, coord) => {
-                                let cast_result = world.cast_ray(&ray);
-                                if worker_tx.send(WorkerMessage::Result(cast_result, coord)).is_err() {
-                                    break;
-                                }
-                                if worker_tx.send(WorkerMessage::Ready).is_err() {
-                                    break;
-                                }
-                            }
-                        }
-
-                    },
-                    Err(_) => break,
-                }
-            }
-        }));
-    }
-
-    pub fn receive_sync(&self) -> Result<WorkerMessage, RecvError> {
-        let receiver = self.worker_rx.as_ref().expect("ParallelWorker not initalized");
-        receiver.recv()
-    }
-
-    pub fn receive_async(&self) -> Result<Option<WorkerMessage>, ()> {
-        let receiver = self.worker_rx.as_ref().expect("ParallelWorker not initalized");
-        match receiver.try_recv() {
-            Ok(message) => Ok(Some(message)),
-            Err(TryRecvError::Empty) => Ok(None),
-            Err(_) => Err(()),
-        }
-    }
-
-    pub fn send(&self, message: ControlMessage) -> Result<(), SendError<ControlMessage>> {
-        let sender = self.control_tx.as_ref().expect("ParallelWorker not initialized");
-        sender.send(message)
-    }
-
-    pub fn join(&mut self) -> Result<(), ()>{
-        let handle = self.join_handle.take().expect("ParallelWorker not initialized");
-        match handle.join() {
-            Ok(_) => Ok(()),
-            Err(_) => Err(())
+fn build_tiles(width: usize, height: usize) -> Vec<Tile> {
+    let mut tiles = Vec::new();
+
+    let mut y = 0;
+    while y < height {
+        let mut x = 0;
+        while x < width {
+            tiles.push(Tile {  x: x,
+                                y: y,
+                                width: (width - x).min(TILE_SIZE),
+                                height: (height - y).min(TILE_SIZE) });
+            x += TILE_SIZE;
         }
+        y += TILE_SIZE;
     }
-}
-
 
-enum ParallelRenderedInternalError {
-    FailedWorker(usize),
-    FailedWorkerWithControlMessage(usize, ControlMessage),
-    EndOfViewIteration
+    tiles
 }
 
-
+/// Screen-space, work-stealing tile renderer. The screen is partitioned into `TILE_SIZE` x
+/// `TILE_SIZE` tiles up front; every worker thread repeatedly grabs the next unclaimed tile off
+/// a shared atomic counter, renders every pixel of that tile into a thread-local buffer, and
+/// only then takes the output lock to write the finished tile straight into the shared output.
+/// This replaces the previous per-ray `mpsc` ready/result handshake (and its per-ray `println!`
+/// logging), whose channel and synchronization overhead dwarfed the actual shading work for
+/// small tasks.
 pub struct ParalellRenderer<WorldType, OutputType> {
     thread_count: u32,
     world: Arc<WorldType>,
     view: View,
-    output: OutputType,
+    output: Arc<Mutex<OutputType>>,
 }
 
-
 impl<WorldType: 'static + RayCaster + Sync + Send,
-     OutputType: RendererOutput> 
+     OutputType: 'static + RendererOutput + Send>
     ParalellRenderer<WorldType, OutputType> {
 
     pub fn new(thread_count: u32, world: WorldType, view: View, output: OutputType) -> Self {
         Self {  thread_count: thread_count,
                 world: Arc::new(world),
                 view: view,
-                output: output}
+                output: Arc::new(Mutex::new(output))}
     }
 
-    fn process_iteration(workers: &Vec<ParallelWorker<WorldType>>, view_iterator: &mut ViewIterator, output: &mut OutputType) -> Result<(), ParallelRenderedInternalError> {
-        for (worker_index, worker) in workers.iter().enumerate() {
-            let worker_receive_result = worker.receive_async();
-            
-            if let Err(()) = worker_receive_result {
-                return Err(ParallelRenderedInternalError::FailedWorker(worker_index))
-            }
-
-            if let Some(message) = worker_receive_result.unwrap() {
-                match message {
-                    WorkerMessage::Ready => {
-                        match view_iterator.next() {
-                            Some((ray, coord)) => {
-                                println!("Sent ray to: {}, {}", coord.x, coord.y);
-                                if let Err(SendError(message)) = worker.send(ControlMessage::CastRay(ray, coord)) {
-                                    return Err(ParallelRenderedInternalError::FailedWorkerWithControlMessage(worker_index, message))
-                                }
-                            },
-                            None => {
-                                return Err(ParallelRenderedInternalError::EndOfViewIteration)
-                            }
-                        }
-                    }
+    fn render_tile(world: &WorldType, view: &View, tile: &Tile) -> Vec<(Point2Int, Color)> {
+        let mut rendered = Vec::with_capacity(tile.width * tile.height);
 
-                    WorkerMessage::Result(color_option, coord) => {
-                        match color_option {
-                            Some(color) => { println!("Recevied result for: {}, {}", coord.x, coord.y); output.set_output(coord, color); },
-                            None => (),
-                        }
+        for local_y in 0..tile.height {
+            for local_x in 0..tile.width {
+                let coord = Point2Int::new((tile.x + local_x) as i32, (tile.y + local_y) as i32);
+                if let Some(ray) = view.get_ray_for_pixel(coord) {
+                    if let Some(color) = world.cast_ray(&ray) {
+                        rendered.push((coord, color));
                     }
                 }
             }
         }
 
-        Ok(())
-    }
-
-    fn replace_worker(workers: &mut Vec<ParallelWorker<WorldType>>, index: usize, world: &Arc<WorldType>) {
-        workers.swap_remove(index);
-        let mut new_worker = ParallelWorker::new(Arc::clone(world));
-        new_worker.spawn();
-        workers.push(new_worker);
+        rendered
     }
 
     pub fn execute(&mut self) {
-        let mut workers: Vec<ParallelWorker<WorldType>> = Vec::new();
-        for _ in 1..(self.thread_count) {
-            let mut new_worker = ParallelWorker::new(Arc::clone(&self.world));
-            new_worker.spawn();
-            workers.push(new_worker);
-        }
-
-        {
-            let mut view_iterator = ViewIterator::new(&self.view);
-            loop {
-                match Self::process_iteration(&workers, &mut view_iterator, &mut self.output) {
-                    Err(ParallelRenderedInternalError::FailedWorker(worker_index)) => {
-                        Self::replace_worker(&mut workers, worker_index, &self.world);
-                    },
-
-                    Err(ParallelRenderedInternalError::FailedWorkerWithControlMessage(worker_index, message)) => {
-                        Self::replace_worker(&mut workers, worker_index, &self.world);
-                        if let ControlMessage::CastRay(ray, coord) = message {
-                            match self.world.cast_ray(&ray) {
-                                Some(color) => {
-                                    self.output.set_output(coord, color);
-                                }
-                                None => (),
-                            }
-                        }
+        let (width, height) = self.view.get_screen().get_resolution();
+        let tiles = Arc::new(build_tiles(width as usize, height as usize));
+        let next_tile = Arc::new(AtomicUsize::new(0));
+
+        let mut thread_container: Vec<thread::JoinHandle<()>> = Vec::new();
+        for _ in 0..self.thread_count {
+            let world = Arc::clone(&self.world);
+            let view = self.view.clone();
+            let tiles = Arc::clone(&tiles);
+            let next_tile = Arc::clone(&next_tile);
+            let output = Arc::clone(&self.output);
+
+            thread_container.push(thread::spawn(move || {
+                loop {
+                    let tile_index = next_tile.fetch_add(1, Ordering::SeqCst);
+                    if tile_index >= tiles.len() {
+                        break;
                     }
 
-                    Err(ParallelRenderedInternalError::EndOfViewIteration) => {
-                        break;
-                    },
+                    let rendered = Self::render_tile(world.as_ref(), &view, &tiles[tile_index]);
 
-                    _ => (),
+                    let mut output = output.lock().unwrap();
+                    for (coord, color) in rendered {
+                        output.set_output(coord, color);
+                    }
                 }
-            }
+            }));
         }
 
-        for worker in workers.iter() {
-            worker.send(ControlMessage::Exit).is_ok();
-        }
-        for worker in workers.iter_mut() {
-            worker.join().is_ok();
-        }
-        for worker in workers.iter() {
-            let mut done = false;
-            while !done {
-                match worker.receive_sync() {
-                    Ok(message) => {
-                        match message {
-                            WorkerMessage::Result(color_option, coord) => {
-                                match color_option {
-                                    Some(color) => {self.output.set_output(coord, color); },
-                                    None => (),
-                                }
-                            },
-                            _ => (),
-                        }
-                    }
-                    Err(_) => {
-                        done = true;
-                    }
-                }
-            }
+        for thread_joiner in thread_container {
+            thread_joiner.join().unwrap();
         }
     }
 
-    pub fn get_renderer_output(&self) -> &OutputType {
-        &self.output
+    pub fn get_renderer_output(&self) -> Arc<Mutex<OutputType>> {
+        Arc::clone(&self.output)
     }
 }